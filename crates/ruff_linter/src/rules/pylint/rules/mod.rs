@@ -0,0 +1,5 @@
+pub(crate) use unnecessary_dict_index_lookup::*;
+pub(crate) use unnecessary_list_index_lookup::*;
+
+mod unnecessary_dict_index_lookup;
+mod unnecessary_list_index_lookup;
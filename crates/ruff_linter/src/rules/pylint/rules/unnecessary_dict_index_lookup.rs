@@ -3,6 +3,7 @@ use ruff_python_ast::{self as ast, Expr, StmtFor};
 
 use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
 use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::equivalence::{contains_call, is_same_expression};
 use ruff_python_ast::visitor;
 use ruff_python_ast::visitor::Visitor;
 use ruff_text_size::TextRange;
@@ -10,12 +11,15 @@ use ruff_text_size::TextRange;
 use crate::checkers::ast::Checker;
 
 /// ## What it does
-/// Checks for key-based dict accesses during `.items()` iterations.
+/// Checks for key-based dict accesses (including `.get()` calls) during
+/// `.items()` iterations.
 ///
 /// ## Why is this bad?
 /// When iterating over a dict via `.items()`, the current value is already
 /// available alongside its key. Using the key to look up the value is
-/// unnecessary.
+/// unnecessary. This applies to any dict-valued receiver, including
+/// attribute accesses and subscripts (e.g. `self.cache.items()`), as long as
+/// the receiver expression is free of side effects.
 ///
 /// ## Example
 /// ```python
@@ -48,24 +52,27 @@ impl AlwaysFixableViolation for UnnecessaryDictIndexLookup {
 
 /// PLR1733
 pub(crate) fn unnecessary_dict_index_lookup(checker: &mut Checker, stmt_for: &StmtFor) {
-    let Some((dict_name, index_name, value_name)) = dict_items(&stmt_for.iter, &stmt_for.target)
+    let Some((IndexedIterable::DictItems(receiver), index_name, value_name)) =
+        indexed_iterable(&stmt_for.iter, &stmt_for.target)
     else {
         return;
     };
 
     let ranges = {
-        let mut visitor = SubscriptVisitor::new(dict_name, index_name);
+        let mut visitor = SubscriptVisitor::new(receiver, true, index_name, value_name);
         visitor.visit_body(&stmt_for.body);
         visitor.visit_body(&stmt_for.orelse);
         visitor.diagnostic_ranges
     };
 
-    for range in ranges {
+    for (range, is_safe) in ranges {
         let mut diagnostic = Diagnostic::new(UnnecessaryDictIndexLookup, range);
-        diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
-            value_name.to_string(),
-            range,
-        )));
+        let edit = Edit::range_replacement(value_name.to_string(), range);
+        diagnostic.set_fix(if is_safe {
+            Fix::safe_edit(edit)
+        } else {
+            Fix::unsafe_edit(edit)
+        });
         checker.diagnostics.push(diagnostic);
     }
 }
@@ -91,12 +98,14 @@ pub(crate) fn unnecessary_dict_index_lookup_comprehension(checker: &mut Checker,
     };
 
     for comp in generators {
-        let Some((dict_name, index_name, value_name)) = dict_items(&comp.iter, &comp.target) else {
+        let Some((IndexedIterable::DictItems(receiver), index_name, value_name)) =
+            indexed_iterable(&comp.iter, &comp.target)
+        else {
             continue;
         };
 
         let ranges = {
-            let mut visitor = SubscriptVisitor::new(dict_name, index_name);
+            let mut visitor = SubscriptVisitor::new(receiver, true, index_name, value_name);
             visitor.visit_expr(elt.as_ref());
             for expr in &comp.ifs {
                 visitor.visit_expr(expr);
@@ -104,40 +113,82 @@ pub(crate) fn unnecessary_dict_index_lookup_comprehension(checker: &mut Checker,
             visitor.diagnostic_ranges
         };
 
-        for range in ranges {
+        for (range, is_safe) in ranges {
             let mut diagnostic = Diagnostic::new(UnnecessaryDictIndexLookup, range);
-            diagnostic.set_fix(Fix::safe_edit(Edit::range_replacement(
-                value_name.to_string(),
-                range,
-            )));
+            let edit = Edit::range_replacement(value_name.to_string(), range);
+            diagnostic.set_fix(if is_safe {
+                Fix::safe_edit(edit)
+            } else {
+                Fix::unsafe_edit(edit)
+            });
             checker.diagnostics.push(diagnostic);
         }
     }
 }
 
-fn dict_items<'a>(
-    call_expr: &'a Expr,
-    tuple_expr: &'a Expr,
-) -> Option<(&'a str, &'a str, &'a str)> {
+/// The kind of indexed iterable a `for` loop or comprehension unpacks into `(index, value)`.
+///
+/// Shared with [`unnecessary_list_index_lookup`](super::unnecessary_list_index_lookup), which
+/// drives the `enumerate()` half of this same extractor and visitor so the two rules can't
+/// drift apart on soundness (receiver equality, rebind tracking).
+#[derive(Debug)]
+pub(crate) enum IndexedIterable<'a> {
+    /// `receiver.items()`, bound as `(key, value)`. Redundant lookups may use `receiver[key]` or
+    /// the equivalent `receiver.get(key)`.
+    DictItems(&'a Expr),
+    /// `enumerate(receiver)`, bound as `(index, value)`. Redundant lookups may use
+    /// `receiver[index]`; sequences have no `.get()` method, so that form isn't recognized.
+    Enumerate(&'a Expr),
+}
+
+/// Recognizes the `receiver.items()` and `enumerate(receiver[, 0])` forms, extracting the
+/// iterable's receiver along with the names bound to the index and the value.
+pub(crate) fn indexed_iterable<'a>(
+    iter_expr: &'a Expr,
+    target_expr: &'a Expr,
+) -> Option<(IndexedIterable<'a>, &'a str, &'a str)> {
     let ast::ExprCall {
         func, arguments, ..
-    } = call_expr.as_call_expr()?;
-
-    if !arguments.is_empty() {
-        return None;
-    }
-    let Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = func.as_ref() else {
-        return None;
-    };
-    if attr != "items" {
-        return None;
-    }
+    } = iter_expr.as_call_expr()?;
 
-    let Expr::Name(ast::ExprName { id: dict_name, .. }) = value.as_ref() else {
-        return None;
+    let kind = match func.as_ref() {
+        Expr::Attribute(ast::ExprAttribute { value, attr, .. }) if attr == "items" => {
+            if !arguments.is_empty() {
+                return None;
+            }
+            // We can't safely assume that two occurrences of the receiver refer to the same
+            // object if evaluating it could have side effects (e.g. `f().items()`).
+            if contains_call(value) {
+                return None;
+            }
+            IndexedIterable::DictItems(value.as_ref())
+        }
+        Expr::Name(ast::ExprName { id, .. }) if id == "enumerate" => {
+            if !arguments.keywords.is_empty() {
+                return None;
+            }
+            let [iterable, rest @ ..] = arguments.args.as_ref() else {
+                return None;
+            };
+            // Only a `start` of (or defaulting to) `0` keeps the enumerate index aligned with
+            // the receiver's own indices.
+            match rest {
+                [] => {}
+                [Expr::Constant(ast::ExprConstant {
+                    value: ast::Constant::Int(start),
+                    ..
+                })] if start.as_u8() == Some(0) => {}
+                _ => return None,
+            }
+            if contains_call(iterable) {
+                return None;
+            }
+            IndexedIterable::Enumerate(iterable)
+        }
+        _ => return None,
     };
 
-    let Expr::Tuple(ast::ExprTuple { elts, .. }) = tuple_expr else {
+    let Expr::Tuple(ast::ExprTuple { elts, .. }) = target_expr else {
         return None;
     };
     let [index, value] = elts.as_slice() else {
@@ -159,24 +210,42 @@ fn dict_items<'a>(
         return None;
     }
 
-    Some((dict_name, index_name, value_name))
+    Some((kind, index_name, value_name))
 }
 
 #[derive(Debug)]
-struct SubscriptVisitor<'a> {
-    dict_name: &'a str,
+pub(crate) struct SubscriptVisitor<'a> {
+    receiver: &'a Expr,
+    /// Whether `receiver.get(index_name)` is an equivalent redundant lookup alongside
+    /// `receiver[index_name]` (true for dicts, false for plain sequences).
+    allow_get: bool,
     index_name: &'a str,
-    diagnostic_ranges: Vec<TextRange>,
+    value_name: &'a str,
+    pub(crate) diagnostic_ranges: Vec<(TextRange, bool)>,
     modified: bool,
+    /// Whether `index_name` or `value_name` has been rebound since the start of the loop body,
+    /// e.g. via assignment, `del`, a nested `for` target, a walrus, a `with ... as` target, or an
+    /// `except ... as` name. Once `true`, any further replacements are no longer guaranteed to
+    /// preserve semantics, so we mark their fixes as unsafe rather than dropping the diagnostics
+    /// outright.
+    rebound: bool,
 }
 
 impl<'a> SubscriptVisitor<'a> {
-    fn new(dict_name: &'a str, index_name: &'a str) -> Self {
+    pub(crate) fn new(
+        receiver: &'a Expr,
+        allow_get: bool,
+        index_name: &'a str,
+        value_name: &'a str,
+    ) -> Self {
         Self {
-            dict_name,
+            receiver,
+            allow_get,
             index_name,
+            value_name,
             diagnostic_ranges: Vec::new(),
             modified: false,
+            rebound: false,
         }
     }
 }
@@ -186,10 +255,7 @@ impl SubscriptVisitor<'_> {
         let Expr::Subscript(ast::ExprSubscript { value, slice, .. }) = expr else {
             return false;
         };
-        let Expr::Name(ast::ExprName { id, .. }) = value.as_ref() else {
-            return false;
-        };
-        if id == self.dict_name {
+        if is_same_expression(value, self.receiver) {
             let Expr::Name(ast::ExprName { id, .. }) = slice.as_ref() else {
                 return false;
             };
@@ -199,6 +265,21 @@ impl SubscriptVisitor<'_> {
         }
         false
     }
+
+    /// Returns `true` if `expr` is (or, for tuple/list/starred targets, contains) a binding of
+    /// `index_name` or `value_name`.
+    fn binds_tracked_name(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Name(ast::ExprName { id, .. }) => {
+                id == self.index_name || id == self.value_name
+            }
+            Expr::Tuple(ast::ExprTuple { elts, .. }) | Expr::List(ast::ExprList { elts, .. }) => {
+                elts.iter().any(|elt| self.binds_tracked_name(elt))
+            }
+            Expr::Starred(ast::ExprStarred { value, .. }) => self.binds_tracked_name(value),
+            _ => false,
+        }
+    }
 }
 
 impl<'a> Visitor<'_> for SubscriptVisitor<'a> {
@@ -209,18 +290,69 @@ impl<'a> Visitor<'_> for SubscriptVisitor<'a> {
         match stmt {
             Stmt::Assign(ast::StmtAssign { targets, value, .. }) => {
                 self.modified = targets.iter().any(|target| self.is_assignment(target));
+                self.rebound |= targets.iter().any(|target| self.binds_tracked_name(target));
                 self.visit_expr(value);
             }
             Stmt::AnnAssign(ast::StmtAnnAssign { target, value, .. }) => {
                 if let Some(value) = value {
                     self.modified = self.is_assignment(target);
+                    self.rebound |= self.binds_tracked_name(target);
                     self.visit_expr(value);
                 }
             }
             Stmt::AugAssign(ast::StmtAugAssign { target, value, .. }) => {
                 self.modified = self.is_assignment(target);
+                self.rebound |= self.binds_tracked_name(target);
                 self.visit_expr(value);
             }
+            Stmt::Delete(ast::StmtDelete { targets, .. }) => {
+                self.rebound |= targets.iter().any(|target| self.binds_tracked_name(target));
+            }
+            Stmt::For(ast::StmtFor {
+                target,
+                iter,
+                body,
+                orelse,
+                ..
+            }) => {
+                self.rebound |= self.binds_tracked_name(target);
+                self.visit_expr(iter);
+                self.visit_body(body);
+                self.visit_body(orelse);
+            }
+            Stmt::With(ast::StmtWith { items, body, .. }) => {
+                for item in items {
+                    self.visit_expr(&item.context_expr);
+                    if let Some(optional_vars) = &item.optional_vars {
+                        self.rebound |= self.binds_tracked_name(optional_vars);
+                    }
+                }
+                self.visit_body(body);
+            }
+            Stmt::Try(ast::StmtTry {
+                body,
+                handlers,
+                orelse,
+                finalbody,
+                ..
+            }) => {
+                self.visit_body(body);
+                for handler in handlers {
+                    let ast::ExceptHandler::ExceptHandler(ast::ExceptHandlerExceptHandler {
+                        name,
+                        body,
+                        ..
+                    }) = handler;
+                    if let Some(name) = name {
+                        if name.as_str() == self.index_name || name.as_str() == self.value_name {
+                            self.rebound = true;
+                        }
+                    }
+                    self.visit_body(body);
+                }
+                self.visit_body(orelse);
+                self.visit_body(finalbody);
+            }
             _ => visitor::walk_stmt(self, stmt),
         }
     }
@@ -236,19 +368,75 @@ impl<'a> Visitor<'_> for SubscriptVisitor<'a> {
                 range,
                 ..
             }) => {
-                let Expr::Name(ast::ExprName { id, .. }) = value.as_ref() else {
-                    return;
-                };
-                if id == self.dict_name {
-                    let Expr::Name(ast::ExprName { id, .. }) = slice.as_ref() else {
-                        return;
-                    };
-                    if id == self.index_name {
-                        self.diagnostic_ranges.push(*range);
+                if is_same_expression(value, self.receiver) {
+                    if let Expr::Name(ast::ExprName { id, .. }) = slice.as_ref() {
+                        if id == self.index_name {
+                            self.diagnostic_ranges.push((*range, !self.rebound));
+                            return;
+                        }
                     }
                 }
+                // Not a match for the tracked receiver (or not indexed by `index_name`); keep
+                // looking for nested occurrences, e.g. `other[FRUITS[fruit_name]]`.
+                visitor::walk_expr(self, expr);
+            }
+            Expr::Named(ast::ExprNamed { target, value, .. }) => {
+                self.rebound |= self.binds_tracked_name(target);
+                self.visit_expr(value);
+            }
+            Expr::Call(ast::ExprCall {
+                func,
+                arguments,
+                range,
+                ..
+            }) => {
+                if self.allow_get {
+                    if let Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = func.as_ref()
+                    {
+                        if attr == "get"
+                            && arguments.keywords.is_empty()
+                            && is_same_expression(value, self.receiver)
+                        {
+                            // Only a bare `receiver.get(key)` is equivalent to `value`; a call
+                            // with a default (`receiver.get(key, default)`) has different
+                            // semantics when the key is missing, so we leave it alone.
+                            if let [Expr::Name(ast::ExprName { id, .. })] =
+                                arguments.args.as_ref()
+                            {
+                                if id == self.index_name {
+                                    self.diagnostic_ranges.push((*range, !self.rebound));
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                }
+                visitor::walk_expr(self, expr);
             }
             _ => visitor::walk_expr(self, expr),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+    use test_case::test_case;
+
+    use crate::registry::Rule;
+    use crate::test::test_path;
+    use crate::{assert_messages, settings};
+
+    #[test_case(Rule::UnnecessaryDictIndexLookup, Path::new("unnecessary_dict_index_lookup.py"))]
+    fn rules(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.noqa_code(), path.to_string_lossy());
+        let diagnostics = test_path(
+            Path::new("pylint").join(path).as_path(),
+            &settings::LinterSettings::for_rule(rule_code),
+        )?;
+        assert_messages!(snapshot, diagnostics);
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use ruff_python_ast::{self as ast, Expr, StmtFor};
+
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+
+use crate::checkers::ast::Checker;
+
+use super::unnecessary_dict_index_lookup::{indexed_iterable, IndexedIterable, SubscriptVisitor};
+
+/// ## What it does
+/// Checks for index-based access to a sequence during `enumerate()`
+/// iterations.
+///
+/// ## Why is this bad?
+/// When iterating over a sequence via `enumerate()`, the current value is
+/// already available alongside its index. Using the index to look up the
+/// value is unnecessary.
+///
+/// ## Example
+/// ```python
+/// names = ["Tom", "Dick", "Harry"]
+///
+/// for index, name in enumerate(names):
+///     print(names[index])
+/// ```
+///
+/// Use instead:
+/// ```python
+/// names = ["Tom", "Dick", "Harry"]
+///
+/// for index, name in enumerate(names):
+///     print(name)
+/// ```
+#[violation]
+pub struct UnnecessaryListIndexLookup;
+
+impl AlwaysFixableViolation for UnnecessaryListIndexLookup {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("Unnecessary lookup of list item by index")
+    }
+
+    fn fix_title(&self) -> String {
+        format!("Use existing variable")
+    }
+}
+
+/// PLR1736
+pub(crate) fn unnecessary_list_index_lookup(checker: &mut Checker, stmt_for: &StmtFor) {
+    let Some((IndexedIterable::Enumerate(receiver), index_name, value_name)) =
+        indexed_iterable(&stmt_for.iter, &stmt_for.target)
+    else {
+        return;
+    };
+
+    let ranges = {
+        let mut visitor = SubscriptVisitor::new(receiver, false, index_name, value_name);
+        visitor.visit_body(&stmt_for.body);
+        visitor.visit_body(&stmt_for.orelse);
+        visitor.diagnostic_ranges
+    };
+
+    for (range, is_safe) in ranges {
+        let mut diagnostic = Diagnostic::new(UnnecessaryListIndexLookup, range);
+        let edit = Edit::range_replacement(value_name.to_string(), range);
+        diagnostic.set_fix(if is_safe {
+            Fix::safe_edit(edit)
+        } else {
+            Fix::unsafe_edit(edit)
+        });
+        checker.diagnostics.push(diagnostic);
+    }
+}
+
+/// PLR1736
+pub(crate) fn unnecessary_list_index_lookup_comprehension(checker: &mut Checker, expr: &Expr) {
+    let (Expr::GeneratorExp(ast::ExprGeneratorExp {
+        elt, generators, ..
+    })
+    | Expr::DictComp(ast::ExprDictComp {
+        value: elt,
+        generators,
+        ..
+    })
+    | Expr::SetComp(ast::ExprSetComp {
+        elt, generators, ..
+    })
+    | Expr::ListComp(ast::ExprListComp {
+        elt, generators, ..
+    })) = expr
+    else {
+        return;
+    };
+
+    for comp in generators {
+        let Some((IndexedIterable::Enumerate(receiver), index_name, value_name)) =
+            indexed_iterable(&comp.iter, &comp.target)
+        else {
+            continue;
+        };
+
+        let ranges = {
+            let mut visitor = SubscriptVisitor::new(receiver, false, index_name, value_name);
+            visitor.visit_expr(elt.as_ref());
+            for expr in &comp.ifs {
+                visitor.visit_expr(expr);
+            }
+            visitor.diagnostic_ranges
+        };
+
+        for (range, is_safe) in ranges {
+            let mut diagnostic = Diagnostic::new(UnnecessaryListIndexLookup, range);
+            let edit = Edit::range_replacement(value_name.to_string(), range);
+            diagnostic.set_fix(if is_safe {
+                Fix::safe_edit(edit)
+            } else {
+                Fix::unsafe_edit(edit)
+            });
+            checker.diagnostics.push(diagnostic);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+    use test_case::test_case;
+
+    use crate::registry::Rule;
+    use crate::test::test_path;
+    use crate::{assert_messages, settings};
+
+    #[test_case(Rule::UnnecessaryListIndexLookup, Path::new("unnecessary_list_index_lookup.py"))]
+    fn rules(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.noqa_code(), path.to_string_lossy());
+        let diagnostics = test_path(
+            Path::new("pylint").join(path).as_path(),
+            &settings::LinterSettings::for_rule(rule_code),
+        )?;
+        assert_messages!(snapshot, diagnostics);
+        Ok(())
+    }
+}
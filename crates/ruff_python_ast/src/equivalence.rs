@@ -0,0 +1,131 @@
+use crate::{self as ast, Expr};
+
+/// Returns `true` if `left` and `right` are structurally equivalent, ignoring
+/// source ranges (e.g. [`ruff_text_size::TextRange`]) and any other
+/// non-semantic metadata.
+///
+/// This is a conservative, purely syntactic comparison in the spirit of
+/// clippy's `SpanlessEq`: it only compares the fields of each [`Expr`] that
+/// affect its meaning (names, attributes, constant values, operators, etc.).
+///
+/// Expressions that contain a call are never considered equivalent to
+/// anything, including another occurrence of themselves, since a call may
+/// have side effects or return a different value each time it's evaluated.
+pub fn is_same_expression(left: &Expr, right: &Expr) -> bool {
+    if contains_call(left) || contains_call(right) {
+        return false;
+    }
+    is_same_expression_inner(left, right)
+}
+
+/// Returns `true` if `expr` is a call or contains one, searching through
+/// attribute, subscript, binary and unary operator, tuple, list and starred
+/// sub-expressions. Calls nested inside any other kind of expression (e.g. a
+/// comparison, a boolean operation or a comprehension) are not found.
+pub fn contains_call(expr: &Expr) -> bool {
+    match expr {
+        Expr::Call(_) => true,
+        Expr::Attribute(ast::ExprAttribute { value, .. }) => contains_call(value),
+        Expr::Subscript(ast::ExprSubscript { value, slice, .. }) => {
+            contains_call(value) || contains_call(slice)
+        }
+        Expr::BinOp(ast::ExprBinOp { left, right, .. }) => {
+            contains_call(left) || contains_call(right)
+        }
+        Expr::UnaryOp(ast::ExprUnaryOp { operand, .. }) => contains_call(operand),
+        Expr::Tuple(ast::ExprTuple { elts, .. }) | Expr::List(ast::ExprList { elts, .. }) => {
+            elts.iter().any(contains_call)
+        }
+        Expr::Starred(ast::ExprStarred { value, .. }) => contains_call(value),
+        _ => false,
+    }
+}
+
+fn is_same_expression_inner(left: &Expr, right: &Expr) -> bool {
+    match (left, right) {
+        (Expr::Name(left), Expr::Name(right)) => left.id == right.id,
+        (Expr::Attribute(left), Expr::Attribute(right)) => {
+            left.attr.as_str() == right.attr.as_str()
+                && is_same_expression_inner(&left.value, &right.value)
+        }
+        (Expr::Subscript(left), Expr::Subscript(right)) => {
+            is_same_expression_inner(&left.value, &right.value)
+                && is_same_expression_inner(&left.slice, &right.slice)
+        }
+        (Expr::Constant(left), Expr::Constant(right)) => left.value == right.value,
+        (Expr::BinOp(left), Expr::BinOp(right)) => {
+            left.op == right.op
+                && is_same_expression_inner(&left.left, &right.left)
+                && is_same_expression_inner(&left.right, &right.right)
+        }
+        (Expr::UnaryOp(left), Expr::UnaryOp(right)) => {
+            left.op == right.op && is_same_expression_inner(&left.operand, &right.operand)
+        }
+        (Expr::Tuple(left), Expr::Tuple(right)) => {
+            left.elts.len() == right.elts.len()
+                && left
+                    .elts
+                    .iter()
+                    .zip(&right.elts)
+                    .all(|(left, right)| is_same_expression_inner(left, right))
+        }
+        (Expr::List(left), Expr::List(right)) => {
+            left.elts.len() == right.elts.len()
+                && left
+                    .elts
+                    .iter()
+                    .zip(&right.elts)
+                    .all(|(left, right)| is_same_expression_inner(left, right))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{contains_call, is_same_expression};
+    use ruff_python_parser::parse_expression;
+
+    fn expr(source: &str) -> ruff_python_ast::Expr {
+        parse_expression(source).unwrap().into_expr()
+    }
+
+    #[test]
+    fn same_name() {
+        assert!(is_same_expression(&expr("x"), &expr("x")));
+        assert!(!is_same_expression(&expr("x"), &expr("y")));
+    }
+
+    #[test]
+    fn same_attribute_chain() {
+        assert!(is_same_expression(&expr("self.cache"), &expr("self.cache")));
+        assert!(!is_same_expression(&expr("self.cache"), &expr("self.other")));
+        assert!(!is_same_expression(&expr("self.cache"), &expr("other.cache")));
+    }
+
+    #[test]
+    fn same_subscript() {
+        assert!(is_same_expression(
+            &expr("data[\"x\"]"),
+            &expr("data[\"x\"]")
+        ));
+        assert!(!is_same_expression(
+            &expr("data[\"x\"]"),
+            &expr("data[\"y\"]")
+        ));
+    }
+
+    #[test]
+    fn call_receivers_never_match() {
+        assert!(!is_same_expression(&expr("f()"), &expr("f()")));
+        assert!(!is_same_expression(&expr("f().cache"), &expr("f().cache")));
+    }
+
+    #[test]
+    fn detects_nested_call() {
+        assert!(!contains_call(&expr("x")));
+        assert!(contains_call(&expr("f()")));
+        assert!(contains_call(&expr("self.f().cache")));
+        assert!(contains_call(&expr("data[f()]")));
+    }
+}